@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::fs;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use serde::Deserialize;
+
+/// An action that can be rebound from `keys.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyAction {
+    MoveDown,
+    MoveUp,
+    Select,
+    Quit,
+    ToggleMark,
+    ToggleTree,
+    CursorStart,
+    CursorEnd,
+    CharBack,
+    CharForward,
+    KillBefore,
+    KillLine,
+    DeleteChar,
+    Backspace,
+}
+
+/// User keybindings parsed from `keys.toml`, consulted before the built-in
+/// defaults in `handle_key`.
+#[derive(Default)]
+pub struct Keymap {
+    bindings: HashMap<(KeyModifiers, KeyCode), KeyAction>,
+}
+
+impl Keymap {
+    /// Load `keys.toml` from `<config>/interactive-nix-search/`, returning an
+    /// empty map (so only the built-in defaults apply) when it is absent or
+    /// cannot be parsed.
+    pub fn load() -> Self {
+        let Some(path) =
+            dirs::config_dir().map(|d| d.join("interactive-nix-search").join("keys.toml"))
+        else {
+            return Keymap::default();
+        };
+
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return Keymap::default();
+        };
+
+        let Ok(raw) = toml::from_str::<HashMap<KeyAction, String>>(&contents) else {
+            return Keymap::default();
+        };
+
+        let bindings = raw
+            .into_iter()
+            .filter_map(|(action, spec)| parse_spec(&spec).map(|key| (key, action)))
+            .collect();
+
+        Keymap { bindings }
+    }
+
+    /// The action bound to a key event, if any.
+    pub fn get(&self, modifiers: KeyModifiers, code: KeyCode) -> Option<KeyAction> {
+        self.bindings.get(&(modifiers, code)).copied()
+    }
+}
+
+/// Parse a spec like `"ctrl-j"`, `"down"`, or `"alt-enter"` into its modifiers
+/// and key code.
+fn parse_spec(spec: &str) -> Option<(KeyModifiers, KeyCode)> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut code = None;
+
+    for part in spec.split(['-', '+']) {
+        match part.trim().to_ascii_lowercase().as_str() {
+            "ctrl" => modifiers |= KeyModifiers::CONTROL,
+            "alt" => modifiers |= KeyModifiers::ALT,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            other => code = Some(parse_code(other)?),
+        }
+    }
+
+    Some((modifiers, code?))
+}
+
+fn parse_code(name: &str) -> Option<KeyCode> {
+    Some(match name {
+        "down" => KeyCode::Down,
+        "up" => KeyCode::Up,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "enter" | "return" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "esc" | "escape" => KeyCode::Esc,
+        "space" => KeyCode::Char(' '),
+        "backspace" | "bs" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        s if s.chars().count() == 1 => KeyCode::Char(s.chars().next().unwrap()),
+        _ => return None,
+    })
+}
@@ -1,4 +1,6 @@
+mod config;
 mod nix;
+mod theme;
 mod ui;
 
 use std::io::{self, Write};
@@ -37,12 +39,16 @@ fn main() -> io::Result<()> {
     };
 
     if let Some(selected) = ui::run(&args.flake, viewport)? {
-        let installable = format!("{}#{}", args.flake, selected);
+        let installables: Vec<String> = selected
+            .iter()
+            .map(|name| format!("{}#{}", args.flake, name))
+            .collect();
 
         if args.add {
-            eprintln!("Installing {installable}...");
+            eprintln!("Installing {}...", installables.join(" "));
             let status = Command::new("nix")
-                .args(["profile", "add", &installable])
+                .args(["profile", "add"])
+                .args(&installables)
                 .status()?;
             if !status.success() {
                 std::process::exit(status.code().unwrap_or(1));
@@ -50,7 +56,9 @@ fn main() -> io::Result<()> {
         } else {
             let stdout = io::stdout();
             let mut out = stdout.lock();
-            writeln!(out, "{selected}")?;
+            for name in &selected {
+                writeln!(out, "{name}")?;
+            }
         }
     }
 
@@ -6,6 +6,7 @@ use std::process::Command;
 use std::time::SystemTime;
 
 use serde::Deserialize;
+use serde_json::Value;
 
 const CACHE_MAX_AGE_SECS: u64 = 24 * 60 * 60;
 
@@ -19,6 +20,77 @@ pub struct Package {
     pub description: String,
 }
 
+impl Package {
+    /// Attribute path relative to the flake, e.g. "python314Packages.uv" or "ruff".
+    pub fn attr_path(&self) -> String {
+        if self.package_set.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}.{}", self.package_set, self.name)
+        }
+    }
+}
+
+/// Rich `meta` detail for a single package, fetched lazily via `nix eval`.
+#[derive(Debug, Clone, Default)]
+pub struct Details {
+    pub homepage: Option<String>,
+    pub license: Option<String>,
+    pub maintainers: Vec<String>,
+    pub platforms: Vec<String>,
+    pub long_description: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct MetaEntry {
+    #[serde(default)]
+    homepage: Option<StringOrList>,
+    #[serde(default)]
+    license: Option<Value>,
+    #[serde(default)]
+    maintainers: Vec<Maintainer>,
+    #[serde(default)]
+    platforms: Vec<String>,
+    #[serde(default, rename = "longDescription")]
+    long_description: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl StringOrList {
+    fn into_string(self) -> String {
+        match self {
+            StringOrList::One(s) => s,
+            StringOrList::Many(v) => v.join(", "),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Maintainer {
+    #[serde(default)]
+    name: String,
+}
+
+/// `meta.license` may be a bare string, an object carrying `fullName`, or a
+/// list of either; flatten whatever shape we get into a human-readable name.
+fn license_name(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Object(map) => map.get("fullName").and_then(|v| v.as_str()).map(String::from),
+        Value::Array(items) => {
+            let names: Vec<String> = items.iter().filter_map(license_name).collect();
+            (!names.is_empty()).then(|| names.join(", "))
+        }
+        _ => None,
+    }
+}
+
 #[derive(Deserialize)]
 struct NixSearchEntry {
     #[serde(default)]
@@ -121,3 +193,35 @@ pub fn load_packages(flake: &str) -> io::Result<Vec<Package>> {
 
     parse_packages(&output.stdout)
 }
+
+/// Fetch the full `meta` for a single attribute by evaluating
+/// `<flake>#<attr_path>.meta`. This is slower than `nix search`, so callers are
+/// expected to invoke it lazily (debounced) and cache the result per attr.
+pub fn fetch_details(flake: &str, attr_path: &str) -> io::Result<Details> {
+    let installable = format!("{flake}#{attr_path}.meta");
+
+    let output = Command::new("nix")
+        .args(["eval", "--json", &installable])
+        .output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::other(format!("nix eval failed: {stderr}")));
+    }
+
+    let meta: MetaEntry = serde_json::from_slice(&output.stdout)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(Details {
+        homepage: meta.homepage.map(StringOrList::into_string),
+        license: meta.license.as_ref().and_then(license_name),
+        maintainers: meta
+            .maintainers
+            .into_iter()
+            .map(|m| m.name)
+            .filter(|n| !n.is_empty())
+            .collect(),
+        platforms: meta.platforms,
+        long_description: meta.long_description,
+    })
+}
@@ -0,0 +1,107 @@
+use std::fs;
+
+use ratatui::style::Color;
+use serde::{Deserialize, Deserializer};
+
+/// Colors used throughout the UI. Loaded from `theme.toml` in the config
+/// directory, falling back to the compiled-in defaults for any missing field.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// Name of the highlighted result row.
+    #[serde(deserialize_with = "de_color")]
+    pub selected_name: Color,
+    /// Name of a non-highlighted result row.
+    #[serde(deserialize_with = "de_color")]
+    pub name: Color,
+    /// Version column.
+    #[serde(deserialize_with = "de_color")]
+    pub version: Color,
+    /// Accent applied to fuzzy-matched characters within a name.
+    #[serde(deserialize_with = "de_color")]
+    pub matched_name: Color,
+    /// Description column.
+    #[serde(deserialize_with = "de_color")]
+    pub description: Color,
+    /// Block borders.
+    #[serde(deserialize_with = "de_color")]
+    pub border: Color,
+    /// The `matched/total` counter in the input title.
+    #[serde(deserialize_with = "de_color")]
+    pub counter: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            selected_name: Color::Cyan,
+            name: Color::Green,
+            version: Color::DarkGray,
+            matched_name: Color::Cyan,
+            description: Color::DarkGray,
+            border: Color::Reset,
+            counter: Color::Reset,
+        }
+    }
+}
+
+impl Theme {
+    /// Load `theme.toml` from `<config>/interactive-nix-search/`, falling back
+    /// to [`Theme::default`] when the file is absent or unreadable.
+    pub fn load() -> Self {
+        let Some(path) =
+            dirs::config_dir().map(|d| d.join("interactive-nix-search").join("theme.toml"))
+        else {
+            return Theme::default();
+        };
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents).unwrap_or_default(),
+            Err(_) => Theme::default(),
+        }
+    }
+}
+
+/// Parse either a named ratatui color or a `#rrggbb` hex string.
+fn parse_color(s: &str) -> Result<Color, String> {
+    let s = s.trim();
+
+    if let Some(hex) = s.strip_prefix('#') {
+        if hex.len() != 6 {
+            return Err(format!("invalid hex color: {s}"));
+        }
+        let component = |range: std::ops::Range<usize>| {
+            u8::from_str_radix(&hex[range], 16).map_err(|_| format!("invalid hex color: {s}"))
+        };
+        return Ok(Color::Rgb(component(0..2)?, component(2..4)?, component(4..6)?));
+    }
+
+    Ok(match s.to_ascii_lowercase().as_str() {
+        "reset" => Color::Reset,
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "gray" | "grey" => Color::Gray,
+        "darkgray" | "darkgrey" => Color::DarkGray,
+        "lightred" => Color::LightRed,
+        "lightgreen" => Color::LightGreen,
+        "lightyellow" => Color::LightYellow,
+        "lightblue" => Color::LightBlue,
+        "lightmagenta" => Color::LightMagenta,
+        "lightcyan" => Color::LightCyan,
+        "white" => Color::White,
+        other => return Err(format!("unknown color: {other}")),
+    })
+}
+
+fn de_color<'de, D>(deserializer: D) -> Result<Color, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    parse_color(&s).map_err(serde::de::Error::custom)
+}
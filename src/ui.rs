@@ -1,18 +1,84 @@
+use std::collections::{HashMap, HashSet};
 use std::io::{self, stderr};
+use std::sync::mpsc::{self, Receiver, Sender};
 use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
 use crossterm::execute;
 use crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen};
 use nucleo::pattern::{CaseMatching, Normalization};
-use nucleo::{Config, Nucleo, Utf32String};
+use nucleo::{Config, Matcher, Nucleo, Utf32String};
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
 use ratatui::{Frame, Terminal, TerminalOptions, Viewport};
 
-use crate::nix::Package;
+use crate::config::{KeyAction, Keymap};
+use crate::nix::{Details, Package};
+use crate::theme::Theme;
+
+/// Delay before fetching `meta` for a freshly selected item, so rapid up/down
+/// movement doesn't spawn a `nix eval` per row.
+const DETAILS_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Number of packages fed into the matcher per injector batch while the index
+/// streams in, so the `matched/total` counter climbs live.
+const LOAD_BATCH: usize = 2000;
+
+/// Messages from the background index loader to the event loop.
+enum LoadMsg {
+    Batch(Vec<Package>),
+    Failed(String),
+}
+
+/// Load the index (subprocess or cache) off the UI thread and feed it back in
+/// batches, so the list becomes searchable as soon as the first batch lands.
+fn load_worker(flake: &str, tx: mpsc::Sender<LoadMsg>) {
+    match crate::nix::load_packages(flake) {
+        Ok(packages) => {
+            for chunk in packages.chunks(LOAD_BATCH) {
+                if tx.send(LoadMsg::Batch(chunk.to_vec())).is_err() {
+                    return;
+                }
+            }
+        }
+        Err(e) => {
+            let _ = tx.send(LoadMsg::Failed(e.to_string()));
+        }
+    }
+}
+
+/// Fetch package `meta` off the UI thread: each requested attr is resolved
+/// with the blocking `nix eval` subprocess and sent back (failures resolve to
+/// `Default`, so the event loop caches them and never re-requests).
+fn details_worker(flake: &str, rx: Receiver<String>, tx: Sender<(String, Details)>) {
+    while let Ok(attr) = rx.recv() {
+        let details = crate::nix::fetch_details(flake, &attr).unwrap_or_default();
+        if tx.send((attr, details)).is_err() {
+            return;
+        }
+    }
+}
+
+/// A single row in the flattened tree view.
+enum TreeRow {
+    /// An expandable package-set branch and the number of leaves it holds.
+    Set { name: String, count: usize },
+    /// A leaf package, indexed into `App::packages`.
+    Leaf(usize),
+}
+
+/// State backing the hierarchical tree-browse mode.
+#[derive(Default)]
+struct TreeState {
+    /// Package sets the user has manually expanded.
+    expanded: HashSet<String>,
+    /// Flattened list of currently visible rows.
+    rows: Vec<TreeRow>,
+}
 
 struct App {
     query: String,
@@ -20,32 +86,75 @@ struct App {
     selected: usize,
     matcher: Nucleo<usize>,
     packages: Vec<Package>,
+    theme: Theme,
+    /// Whether the tree-browse view is active instead of the flat fuzzy list.
+    tree_mode: bool,
+    /// Cursor into `tree.rows` while in tree mode.
+    tree_cursor: usize,
+    tree: TreeState,
+    /// Set when the matches or expansion state change and the tree needs
+    /// rebuilding before the next draw.
+    tree_dirty: bool,
+    /// Packages marked for batch selection, by index into `packages`.
+    selected_set: HashSet<usize>,
+    /// User-configured keybindings, consulted before the built-in defaults.
+    keymap: Keymap,
+    /// Fetched `meta` keyed by attr path; also caches failures as `Default` so
+    /// we never re-spawn `nix eval` for the same attr.
+    details_cache: HashMap<String, Details>,
+    /// Attr whose details we're waiting to fetch, and when it was selected.
+    pending_attr: Option<String>,
+    pending_since: Option<Instant>,
+    /// Channel to the background details fetcher; attrs are requested once the
+    /// selection settles so the blocking `nix eval` never runs on this thread.
+    details_tx: Option<Sender<String>>,
+    /// Attrs already handed to the fetcher, so we don't re-request in flight.
+    details_requested: HashSet<String>,
+    /// Fatal error reported by the background loader, if any.
+    load_error: Option<String>,
 }
 
 impl App {
-    fn new(packages: Vec<Package>) -> Self {
+    fn new(theme: Theme) -> Self {
         let matcher = Nucleo::new(Config::DEFAULT.match_paths(), Arc::new(|| {}), None, 1);
 
-        let injector = matcher.injector();
-        for (idx, pkg) in packages.iter().enumerate() {
-            let search_text = format!(
-                "{} {} {} {}",
-                pkg.name, pkg.package_set, pkg.version, pkg.description
-            );
-            injector.push(idx, |_, cols| {
-                cols[0] = Utf32String::from(search_text.as_str());
-            });
-        }
-
         App {
             query: String::new(),
             cursor: 0,
             selected: 0,
             matcher,
-            packages,
+            packages: Vec::new(),
+            theme,
+            tree_mode: false,
+            tree_cursor: 0,
+            tree: TreeState::default(),
+            tree_dirty: false,
+            selected_set: HashSet::new(),
+            keymap: Keymap::load(),
+            details_cache: HashMap::new(),
+            pending_attr: None,
+            pending_since: None,
+            details_tx: None,
+            details_requested: HashSet::new(),
+            load_error: None,
         }
     }
 
+    /// Append a freshly-loaded package and feed it into the matcher. Indices
+    /// stay in sync with `packages` because both grow together on this thread.
+    fn push_package(&mut self, pkg: Package) {
+        let idx = self.packages.len();
+        let search_text = format!(
+            "{} {} {} {}",
+            pkg.name, pkg.package_set, pkg.version, pkg.description
+        );
+        self.matcher.injector().push(idx, |_, cols| {
+            cols[0] = Utf32String::from(search_text.as_str());
+        });
+        self.packages.push(pkg);
+        self.tree_dirty = true;
+    }
+
     fn update_pattern(&mut self) {
         self.matcher.pattern.reparse(
             0,
@@ -54,6 +163,55 @@ impl App {
             Normalization::Smart,
             false,
         );
+        self.tree_dirty = true;
+    }
+
+    /// Rebuild the flattened tree rows from the current matches, grouping
+    /// matched packages by `package_set`. While a query is active every set
+    /// that contains a match is auto-expanded.
+    fn rebuild_tree(&mut self) {
+        let filtering = !self.query.is_empty();
+        let snapshot = self.matcher.snapshot();
+
+        let mut order: Vec<String> = Vec::new();
+        let mut groups: HashMap<String, Vec<usize>> = HashMap::new();
+        for i in 0..snapshot.matched_item_count() {
+            let Some(item) = snapshot.get_matched_item(i) else {
+                break;
+            };
+            let pkg_idx = *item.data;
+            let set = &self.packages[pkg_idx].package_set;
+            groups
+                .entry(set.clone())
+                .or_insert_with(|| {
+                    order.push(set.clone());
+                    Vec::new()
+                })
+                .push(pkg_idx);
+        }
+
+        order.sort();
+        let mut rows = Vec::new();
+        for set in &order {
+            let leaves = &groups[set];
+            if set.is_empty() {
+                // Top-level attrs live at the root, not under a branch.
+                rows.extend(leaves.iter().map(|&idx| TreeRow::Leaf(idx)));
+                continue;
+            }
+            rows.push(TreeRow::Set {
+                name: set.clone(),
+                count: leaves.len(),
+            });
+            if filtering || self.tree.expanded.contains(set) {
+                rows.extend(leaves.iter().map(|&idx| TreeRow::Leaf(idx)));
+            }
+        }
+
+        self.tree.rows = rows;
+        if self.tree_cursor >= self.tree.rows.len() {
+            self.tree_cursor = self.tree.rows.len().saturating_sub(1);
+        }
     }
 
     fn matched_count(&self) -> u32 {
@@ -64,13 +222,248 @@ impl App {
         self.matcher.snapshot().item_count()
     }
 
-    fn get_matched_package(&self, index: u32) -> Option<&Package> {
-        let item = self.matcher.snapshot().get_matched_item(index)?;
-        Some(&self.packages[*item.data])
+    /// True while the background loader is still producing its first batch, so
+    /// the empty view can show "Loading…" rather than a misleading "No matches".
+    fn is_loading(&self) -> bool {
+        self.packages.is_empty() && self.load_error.is_none()
+    }
+
+    /// Index into `packages` of the item under the cursor in whichever view is
+    /// active (none when a tree branch is focused).
+    fn selected_index(&self) -> Option<usize> {
+        if self.tree_mode {
+            match self.tree.rows.get(self.tree_cursor)? {
+                TreeRow::Leaf(idx) => Some(*idx),
+                TreeRow::Set { .. } => None,
+            }
+        } else {
+            let item = self.matcher.snapshot().get_matched_item(self.selected as u32)?;
+            Some(*item.data)
+        }
+    }
+
+    /// The package under the cursor in whichever view is active.
+    fn selected_package(&self) -> Option<&Package> {
+        self.selected_index().map(|idx| &self.packages[idx])
+    }
+
+    /// Toggle the item under the cursor in the batch selection.
+    fn toggle_mark(&mut self) {
+        if let Some(idx) = self.selected_index()
+            && !self.selected_set.remove(&idx)
+        {
+            self.selected_set.insert(idx);
+        }
+    }
+
+    /// Attr paths to return on Enter: every marked package (sorted by index
+    /// for a stable order), or just the cursor item when nothing is marked.
+    /// Returning the full attr path keeps selections inside a package set
+    /// installable (`python314Packages.uv`, not the bare `uv`).
+    fn chosen_names(&self) -> Vec<String> {
+        if self.selected_set.is_empty() {
+            return self
+                .selected_package()
+                .map(Package::attr_path)
+                .into_iter()
+                .collect();
+        }
+        let mut indices: Vec<usize> = self.selected_set.iter().copied().collect();
+        indices.sort_unstable();
+        indices
+            .into_iter()
+            .map(|idx| self.packages[idx].attr_path())
+            .collect()
+    }
+
+    /// Attr path of the currently selected package, if any.
+    fn selected_attr(&self) -> Option<String> {
+        self.selected_package().map(Package::attr_path)
+    }
+
+    /// Move the selection down one row in whichever view is active.
+    fn move_down(&mut self) {
+        if self.tree_mode {
+            self.tree_move(1);
+            return;
+        }
+        let count = self.matched_count();
+        if count > 0 && (self.selected as u32) < count - 1 {
+            self.selected += 1;
+        }
+    }
+
+    /// Move the selection up one row in whichever view is active.
+    fn move_up(&mut self) {
+        if self.tree_mode {
+            self.tree_move(-1);
+        } else if self.selected > 0 {
+            self.selected -= 1;
+        }
+    }
+
+    fn cursor_start(&mut self) {
+        self.cursor = 0;
+    }
+
+    fn cursor_end(&mut self) {
+        self.cursor = self.query.len();
+    }
+
+    fn char_back(&mut self) {
+        if self.cursor > 0 {
+            self.cursor = self.query[..self.cursor]
+                .char_indices()
+                .next_back()
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+        }
+    }
+
+    fn char_forward(&mut self) {
+        if self.cursor < self.query.len() {
+            self.cursor += self.query[self.cursor..]
+                .chars()
+                .next()
+                .map_or(0, |c| c.len_utf8());
+        }
+    }
+
+    fn kill_before(&mut self) {
+        self.query.drain(..self.cursor);
+        self.cursor = 0;
+        self.selected = 0;
+        self.update_pattern();
+    }
+
+    fn kill_line(&mut self) {
+        self.query.truncate(self.cursor);
+        self.selected = 0;
+        self.update_pattern();
+    }
+
+    fn delete_char(&mut self) {
+        if self.cursor < self.query.len() {
+            let next = self.cursor
+                + self.query[self.cursor..]
+                    .chars()
+                    .next()
+                    .map_or(0, |c| c.len_utf8());
+            self.query.drain(self.cursor..next);
+            self.selected = 0;
+            self.update_pattern();
+        }
+    }
+
+    fn backspace(&mut self) {
+        if self.cursor > 0 {
+            let prev = self.query[..self.cursor]
+                .char_indices()
+                .next_back()
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+            self.query.drain(prev..self.cursor);
+            self.cursor = prev;
+            self.selected = 0;
+            self.update_pattern();
+        }
+    }
+
+    fn toggle_tree(&mut self) {
+        self.tree_mode = !self.tree_mode;
+        self.tree_dirty = true;
+    }
+
+    /// Run a keybound action, returning the resulting control-flow signal.
+    fn dispatch_action(&mut self, action: KeyAction) -> Action {
+        match action {
+            KeyAction::MoveDown => self.move_down(),
+            KeyAction::MoveUp => self.move_up(),
+            KeyAction::Select => return Action::Select,
+            KeyAction::Quit => return Action::Quit,
+            KeyAction::ToggleMark => self.toggle_mark(),
+            KeyAction::ToggleTree => self.toggle_tree(),
+            KeyAction::CursorStart => self.cursor_start(),
+            KeyAction::CursorEnd => self.cursor_end(),
+            KeyAction::CharBack => self.char_back(),
+            KeyAction::CharForward => self.char_forward(),
+            KeyAction::KillBefore => self.kill_before(),
+            KeyAction::KillLine => self.kill_line(),
+            KeyAction::DeleteChar => self.delete_char(),
+            KeyAction::Backspace => self.backspace(),
+        }
+        Action::Continue
+    }
+
+    /// Move the tree cursor by one row, clamped to the visible rows.
+    fn tree_move(&mut self, delta: isize) {
+        let len = self.tree.rows.len();
+        if len == 0 {
+            return;
+        }
+        let next = (self.tree_cursor as isize + delta).clamp(0, len as isize - 1);
+        self.tree_cursor = next as usize;
+    }
+
+    /// Expand the set under the cursor, or descend onto its first child.
+    fn tree_expand(&mut self) {
+        if let Some(TreeRow::Set { name, .. }) = self.tree.rows.get(self.tree_cursor) {
+            if self.tree.expanded.insert(name.clone()) {
+                self.tree_dirty = true;
+            } else {
+                self.tree_move(1);
+            }
+        }
+    }
+
+    /// Collapse the set under the cursor, or ascend to a leaf's parent set.
+    fn tree_collapse(&mut self) {
+        match self.tree.rows.get(self.tree_cursor) {
+            Some(TreeRow::Set { name, .. }) if self.tree.expanded.remove(name) => {
+                self.tree_dirty = true;
+            }
+            Some(TreeRow::Set { .. }) => {}
+            Some(TreeRow::Leaf(_)) => {
+                let parent = (0..self.tree_cursor)
+                    .rev()
+                    .find(|&i| matches!(self.tree.rows[i], TreeRow::Set { .. }));
+                if let Some(i) = parent {
+                    self.tree_cursor = i;
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Request `meta` for the selection once it has been stable for
+    /// [`DETAILS_DEBOUNCE`], handing the attr to the background fetcher (at
+    /// most once per attr). The result lands in `details_cache` when it
+    /// arrives; see [`details_worker`].
+    fn poll_details(&mut self) {
+        let attr = self.selected_attr();
+        if attr != self.pending_attr {
+            self.pending_attr = attr;
+            self.pending_since = Some(Instant::now());
+        }
+
+        let Some(attr) = self.pending_attr.clone() else {
+            return;
+        };
+        if self.details_cache.contains_key(&attr) || self.details_requested.contains(&attr) {
+            return;
+        }
+        if self.pending_since.is_some_and(|t| t.elapsed() >= DETAILS_DEBOUNCE) {
+            if let Some(tx) = &self.details_tx
+                && tx.send(attr.clone()).is_ok()
+            {
+                self.details_requested.insert(attr);
+            }
+            self.pending_since = None;
+        }
     }
 }
 
-pub fn run(flake: &str, viewport: Viewport) -> io::Result<Option<String>> {
+pub fn run(flake: &str, viewport: Viewport) -> io::Result<Option<Vec<String>>> {
     let fullscreen = matches!(viewport, Viewport::Fullscreen);
 
     if fullscreen {
@@ -84,32 +477,27 @@ pub fn run(flake: &str, viewport: Viewport) -> io::Result<Option<String>> {
 
     crossterm::terminal::enable_raw_mode()?;
 
-    // Show loading message inside the viewport
-    let loading_msg = format!("Loading {flake} index...");
-    terminal.draw(|f| {
-        let area = f.area();
-        let msg = Paragraph::new(loading_msg.as_str()).style(Style::default().fg(Color::DarkGray));
-        f.render_widget(msg, area);
-    })?;
-
-    let packages = match crate::nix::load_packages(flake) {
-        Ok(p) if !p.is_empty() => p,
-        Ok(_) => {
-            cleanup(&mut terminal, fullscreen)?;
-            eprintln!("No packages found.");
-            return Ok(None);
-        }
-        Err(e) => {
-            cleanup(&mut terminal, fullscreen)?;
-            return Err(e);
-        }
-    };
+    let mut app = App::new(Theme::load());
+
+    // Load the index on a background thread and stream it into the matcher.
+    let (tx, rx) = mpsc::channel();
+    let flake_owned = flake.to_string();
+    let loader = thread::spawn(move || load_worker(&flake_owned, tx));
 
-    let mut app = App::new(packages);
-    app.matcher.tick(10);
+    // Fetch the preview `meta` on a second background thread so the blocking
+    // `nix eval` never freezes the event loop.
+    let (detail_req_tx, detail_req_rx) = mpsc::channel();
+    let (detail_res_tx, detail_res_rx) = mpsc::channel();
+    app.details_tx = Some(detail_req_tx);
+    let flake_owned = flake.to_string();
+    let fetcher = thread::spawn(move || details_worker(&flake_owned, detail_req_rx, detail_res_tx));
 
-    let result = run_loop(&mut terminal, &mut app);
+    let result = run_loop(&mut terminal, &mut app, &rx, &detail_res_rx);
 
+    // Drop the request sender so the fetcher's `recv` returns and it exits.
+    app.details_tx = None;
+    let _ = loader.join();
+    let _ = fetcher.join();
     cleanup(&mut terminal, fullscreen)?;
 
     result
@@ -131,9 +519,41 @@ fn cleanup(
 fn run_loop(
     terminal: &mut Terminal<ratatui::backend::CrosstermBackend<io::Stderr>>,
     app: &mut App,
-) -> io::Result<Option<String>> {
+    rx: &Receiver<LoadMsg>,
+    detail_rx: &Receiver<(String, Details)>,
+) -> io::Result<Option<Vec<String>>> {
     loop {
+        // Surface one batch per frame so the matched/total counter climbs live
+        // instead of jumping 0→full in a single redraw when a large (cached)
+        // index is parsed and queued all at once.
+        match rx.try_recv() {
+            Ok(LoadMsg::Batch(batch)) => {
+                for pkg in batch {
+                    app.push_package(pkg);
+                }
+            }
+            Ok(LoadMsg::Failed(e)) => app.load_error = Some(e),
+            Err(_) => {}
+        }
+
+        // Fold in any preview details the fetcher has resolved.
+        while let Ok((attr, details)) = detail_rx.try_recv() {
+            app.details_cache.insert(attr, details);
+        }
+
+        if app.packages.is_empty()
+            && let Some(e) = &app.load_error
+        {
+            return Err(io::Error::other(e.clone()));
+        }
+
         app.matcher.tick(10);
+        app.poll_details();
+
+        if app.tree_mode && app.tree_dirty {
+            app.rebuild_tree();
+            app.tree_dirty = false;
+        }
 
         terminal.draw(|f| render(f, app))?;
 
@@ -144,10 +564,8 @@ fn run_loop(
                 Action::Continue => {}
                 Action::Quit => return Ok(None),
                 Action::Select => {
-                    if let Some(pkg) = app.get_matched_package(app.selected as u32) {
-                        return Ok(Some(pkg.name.clone()));
-                    }
-                    return Ok(None);
+                    let names = app.chosen_names();
+                    return Ok((!names.is_empty()).then_some(names));
                 }
             }
         }
@@ -161,22 +579,82 @@ enum Action {
 }
 
 fn handle_key(app: &mut App, key: KeyEvent) -> Action {
+    // User-configured bindings take precedence over the built-in defaults.
+    if let Some(action) = app.keymap.get(key.modifiers, key.code) {
+        return app.dispatch_action(action);
+    }
+
+    // Tree-mode navigation. Unhandled keys (printable characters, editing)
+    // fall through to the shared keymap below so typing still filters.
+    if app.tree_mode {
+        match (key.modifiers, key.code) {
+            (_, KeyCode::Left) => {
+                app.tree_collapse();
+                return Action::Continue;
+            }
+            (_, KeyCode::Right) => {
+                app.tree_expand();
+                return Action::Continue;
+            }
+            (_, KeyCode::Enter) => {
+                return match app.tree.rows.get(app.tree_cursor) {
+                    Some(TreeRow::Leaf(_)) => Action::Select,
+                    Some(TreeRow::Set { name, .. }) => {
+                        let name = name.clone();
+                        if !app.tree.expanded.remove(&name) {
+                            app.tree.expanded.insert(name);
+                        }
+                        app.tree_dirty = true;
+                        Action::Continue
+                    }
+                    None => Action::Continue,
+                };
+            }
+            // vi-style navigation, only while the query is empty so typing
+            // `h`/`j`/`k`/`l` into a search still filters as expected.
+            (_, KeyCode::Char('h')) if app.query.is_empty() => {
+                app.tree_collapse();
+                return Action::Continue;
+            }
+            (_, KeyCode::Char('l')) if app.query.is_empty() => {
+                app.tree_expand();
+                return Action::Continue;
+            }
+            (_, KeyCode::Char('j')) if app.query.is_empty() => {
+                app.move_down();
+                return Action::Continue;
+            }
+            (_, KeyCode::Char('k')) if app.query.is_empty() => {
+                app.move_up();
+                return Action::Continue;
+            }
+            _ => {}
+        }
+    }
+
     match (key.modifiers, key.code) {
         (_, KeyCode::Esc) => Action::Quit,
         (KeyModifiers::CONTROL, KeyCode::Char('c')) => Action::Quit,
 
+        // Toggle the tree-browse view
+        (KeyModifiers::CONTROL, KeyCode::Char('t')) => {
+            app.toggle_tree();
+            Action::Continue
+        }
+
         // Result navigation
-        (KeyModifiers::CONTROL, KeyCode::Char('n')) => {
-            let count = app.matched_count();
-            if count > 0 && (app.selected as u32) < count - 1 {
-                app.selected += 1;
-            }
+        (_, KeyCode::Down) | (KeyModifiers::CONTROL, KeyCode::Char('n')) => {
+            app.move_down();
             Action::Continue
         }
-        (KeyModifiers::CONTROL, KeyCode::Char('p')) => {
-            if app.selected > 0 {
-                app.selected -= 1;
-            }
+        (_, KeyCode::Up) | (KeyModifiers::CONTROL, KeyCode::Char('p')) => {
+            app.move_up();
+            Action::Continue
+        }
+
+        // Mark / unmark the current item for batch selection
+        (_, KeyCode::Tab) | (KeyModifiers::CONTROL, KeyCode::Char(' ')) => {
+            app.toggle_mark();
             Action::Continue
         }
 
@@ -185,72 +663,37 @@ fn handle_key(app: &mut App, key: KeyEvent) -> Action {
 
         // Cursor movement
         (KeyModifiers::CONTROL, KeyCode::Char('a')) => {
-            app.cursor = 0;
+            app.cursor_start();
             Action::Continue
         }
         (KeyModifiers::CONTROL, KeyCode::Char('e')) => {
-            app.cursor = app.query.len();
+            app.cursor_end();
             Action::Continue
         }
         (KeyModifiers::CONTROL, KeyCode::Char('b')) => {
-            if app.cursor > 0 {
-                app.cursor = app.query[..app.cursor]
-                    .char_indices()
-                    .next_back()
-                    .map(|(i, _)| i)
-                    .unwrap_or(0);
-            }
+            app.char_back();
             Action::Continue
         }
         (KeyModifiers::CONTROL, KeyCode::Char('f')) => {
-            if app.cursor < app.query.len() {
-                app.cursor += app.query[app.cursor..]
-                    .chars()
-                    .next()
-                    .map_or(0, |c| c.len_utf8());
-            }
+            app.char_forward();
             Action::Continue
         }
 
         // Editing
         (KeyModifiers::CONTROL, KeyCode::Char('u')) => {
-            app.query.drain(..app.cursor);
-            app.cursor = 0;
-            app.selected = 0;
-            app.update_pattern();
+            app.kill_before();
             Action::Continue
         }
         (KeyModifiers::CONTROL, KeyCode::Char('k')) => {
-            app.query.truncate(app.cursor);
-            app.selected = 0;
-            app.update_pattern();
+            app.kill_line();
             Action::Continue
         }
         (KeyModifiers::CONTROL, KeyCode::Char('d')) => {
-            if app.cursor < app.query.len() {
-                let next = app.cursor
-                    + app.query[app.cursor..]
-                        .chars()
-                        .next()
-                        .map_or(0, |c| c.len_utf8());
-                app.query.drain(app.cursor..next);
-                app.selected = 0;
-                app.update_pattern();
-            }
+            app.delete_char();
             Action::Continue
         }
         (_, KeyCode::Backspace) => {
-            if app.cursor > 0 {
-                let prev = app.query[..app.cursor]
-                    .char_indices()
-                    .next_back()
-                    .map(|(i, _)| i)
-                    .unwrap_or(0);
-                app.query.drain(prev..app.cursor);
-                app.cursor = prev;
-                app.selected = 0;
-                app.update_pattern();
-            }
+            app.backspace();
             Action::Continue
         }
         (_, KeyCode::Char(c)) => {
@@ -268,21 +711,175 @@ fn handle_key(app: &mut App, key: KeyEvent) -> Action {
 fn render(f: &mut Frame, app: &App) {
     let area = f.area();
 
+    let [results_col, preview_col] =
+        Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(area);
+
     let [input_area, results_area] =
-        Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).areas(area);
+        Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).areas(results_col);
 
-    render_input(f, app, input_area);
-    render_results(f, app, results_area);
+    let theme = &app.theme;
+    render_input(f, app, input_area, theme);
+    if app.tree_mode {
+        render_tree(f, app, results_area, theme);
+    } else {
+        render_results(f, app, results_area, theme);
+    }
+    render_preview(f, app, preview_col, theme);
 }
 
-fn render_input(f: &mut Frame, app: &App, area: Rect) {
+fn render_tree(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let rows = &app.tree.rows;
+    if rows.is_empty() {
+        let text = if app.is_loading() { "  Loading…" } else { "  No matches" };
+        let empty = Paragraph::new(text).style(Style::default().fg(Color::DarkGray));
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let filtering = !app.query.is_empty();
+    let height = area.height as usize;
+    let start = app
+        .tree_cursor
+        .saturating_sub(height / 2)
+        .min(rows.len().saturating_sub(height));
+
+    let items: Vec<ListItem> = rows
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(height)
+        .map(|(i, row)| {
+            let is_selected = i == app.tree_cursor;
+            let line = match row {
+                TreeRow::Set { name, count } => {
+                    let expanded = filtering || app.tree.expanded.contains(name);
+                    let glyph = if expanded { "▾ " } else { "▸ " };
+                    let style = if is_selected {
+                        Style::default()
+                            .fg(theme.selected_name)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(theme.name)
+                    };
+                    Line::from(vec![
+                        Span::styled(glyph.to_string(), style),
+                        Span::styled(name.clone(), style),
+                        Span::styled(format!(" ({count})"), Style::default().fg(theme.version)),
+                    ])
+                }
+                TreeRow::Leaf(idx) => {
+                    let pkg = &app.packages[*idx];
+                    let name_style = if is_selected {
+                        Style::default()
+                            .fg(theme.selected_name)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(theme.name)
+                    };
+                    let mark_glyph = if app.selected_set.contains(idx) {
+                        "  ◉ "
+                    } else {
+                        "    "
+                    };
+                    Line::from(vec![
+                        Span::styled(mark_glyph.to_string(), name_style),
+                        Span::styled(pkg.name.clone(), name_style),
+                        Span::styled(format!("  {}", pkg.version), Style::default().fg(theme.version)),
+                        Span::styled(
+                            format!("  {}", pkg.description),
+                            Style::default().fg(theme.description),
+                        ),
+                    ])
+                }
+            };
+            ListItem::new(line)
+        })
+        .collect();
+
+    f.render_widget(List::new(items), area);
+}
+
+fn render_preview(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(" Details ");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let Some(pkg) = app.selected_package() else {
+        return;
+    };
+
+    let mut lines: Vec<Line> = Vec::new();
+    let heading = Style::default().fg(theme.description);
+
+    let display_name = pkg.attr_path();
+    lines.push(Line::from(Span::styled(
+        display_name,
+        Style::default()
+            .fg(theme.selected_name)
+            .add_modifier(Modifier::BOLD),
+    )));
+    if !pkg.version.is_empty() {
+        lines.push(Line::from(Span::styled(
+            pkg.version.clone(),
+            Style::default().fg(theme.version),
+        )));
+    }
+
+    match app.details_cache.get(&pkg.attr_path()) {
+        None => {
+            lines.push(Line::default());
+            lines.push(Line::from(Span::styled("Loading…", heading)));
+        }
+        Some(details) => {
+            let mut field = |label: &str, value: &str| {
+                if !value.is_empty() {
+                    lines.push(Line::default());
+                    lines.push(Line::from(Span::styled(label.to_string(), heading)));
+                    lines.push(Line::from(value.to_string()));
+                }
+            };
+
+            if let Some(homepage) = &details.homepage {
+                field("Homepage", homepage);
+            }
+            if let Some(license) = &details.license {
+                field("License", license);
+            }
+            if !details.maintainers.is_empty() {
+                field("Maintainers", &details.maintainers.join(", "));
+            }
+            if !details.platforms.is_empty() {
+                field("Platforms", &details.platforms.join(", "));
+            }
+
+            let description = details
+                .long_description
+                .as_deref()
+                .filter(|s| !s.trim().is_empty())
+                .unwrap_or(&pkg.description);
+            field("Description", description.trim());
+        }
+    }
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true });
+    f.render_widget(paragraph, inner);
+}
+
+fn render_input(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
     let matched = app.matched_count();
     let total = app.total_count();
 
     let input = Paragraph::new(app.query.as_str()).block(
         Block::default()
             .borders(Borders::ALL)
-            .title(format!(" {matched}/{total} ")),
+            .border_style(Style::default().fg(theme.border))
+            .title(Span::styled(
+                format!(" {matched}/{total} "),
+                Style::default().fg(theme.counter),
+            )),
     );
     f.render_widget(input, area);
 
@@ -292,13 +889,38 @@ fn render_input(f: &mut Frame, app: &App, area: Rect) {
     f.set_cursor_position((cursor_x, cursor_y));
 }
 
-fn render_results(f: &mut Frame, app: &App, area: Rect) {
+/// Split `name` into alternating styled runs, applying `accent` to characters
+/// at the given (sorted, char-indexed) match positions and `base` to the rest.
+fn highlight_spans(name: &str, matched: &[u32], base: Style, accent: Style) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+
+    for (char_idx, ch) in name.chars().enumerate() {
+        let is_match = matched.binary_search(&(char_idx as u32)).is_ok();
+        if !run.is_empty() && is_match != run_matched {
+            let style = if run_matched { accent } else { base };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_matched = is_match;
+        run.push(ch);
+    }
+    if !run.is_empty() {
+        let style = if run_matched { accent } else { base };
+        spans.push(Span::styled(run, style));
+    }
+
+    spans
+}
+
+fn render_results(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
     let snapshot = app.matcher.snapshot();
     let visible_count = area.height as u32;
     let matched_count = snapshot.matched_item_count();
 
     if matched_count == 0 {
-        let empty = Paragraph::new("  No matches").style(Style::default().fg(Color::DarkGray));
+        let text = if app.is_loading() { "  Loading…" } else { "  No matches" };
+        let empty = Paragraph::new(text).style(Style::default().fg(Color::DarkGray));
         f.render_widget(empty, area);
         return;
     }
@@ -327,59 +949,81 @@ fn render_results(f: &mut Frame, app: &App, area: Rect) {
     let visible: Vec<_> = (start..end)
         .filter_map(|i| {
             let item = snapshot.get_matched_item(i)?;
-            let pkg = &app.packages[*item.data];
-            let display_name = if pkg.package_set.is_empty() {
-                pkg.name.clone()
-            } else {
-                format!("{}.{}", pkg.package_set, pkg.name)
-            };
-            Some((i, pkg, display_name))
+            let pkg_idx = *item.data;
+            let pkg = &app.packages[pkg_idx];
+            let display_name = pkg.attr_path();
+            Some((i, pkg_idx, pkg, display_name))
         })
         .collect();
 
-    let name_w = visible.iter().map(|(_, _, n)| n.len()).max().unwrap_or(0) + 2;
+    let name_w = visible.iter().map(|(_, _, _, n)| n.len()).max().unwrap_or(0) + 2;
     let ver_w = visible
         .iter()
-        .map(|(_, p, _)| p.version.len())
+        .map(|(_, _, p, _)| p.version.len())
         .max()
         .unwrap_or(0)
         + 2;
 
+    // Reusable matcher and scratch buffer for computing per-row match indices
+    // against the display name, so matched characters can be highlighted.
+    let mut matcher = Matcher::new(Config::DEFAULT);
+    let pattern = app.matcher.pattern.column_pattern(0);
+    let mut indices: Vec<u32> = Vec::new();
+
     let items: Vec<ListItem> = visible
         .iter()
-        .map(|(i, pkg, display_name)| {
+        .map(|(i, pkg_idx, pkg, display_name)| {
             let is_selected = *i == selected;
+            let is_marked = app.selected_set.contains(pkg_idx);
 
             let name_style = if is_selected {
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme.selected_name)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.name)
             };
 
-            let ver_style = if is_selected {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default().fg(Color::DarkGray)
-            };
+            let matched_style = Style::default()
+                .fg(theme.matched_name)
+                .add_modifier(Modifier::BOLD);
+
+            let ver_style = Style::default().fg(theme.version);
 
             let desc_style = if is_selected {
-                Style::default().fg(Color::White)
+                Style::default()
+                    .fg(theme.description)
+                    .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme.description)
             };
 
-            let marker = if is_selected { "â–¸ " } else { "  " };
+            let cursor_glyph = if is_selected { "▸" } else { " " };
+            let mark_glyph = if is_marked { "◉" } else { " " };
+            let marker = format!("{mark_glyph}{cursor_glyph} ");
 
-            let line = Line::from(vec![
-                Span::styled(marker, name_style),
-                Span::styled(format!("{:<name_w$}", display_name), name_style),
-                Span::styled(format!("{:<ver_w$}", pkg.version), ver_style),
-                Span::styled(&pkg.description, desc_style),
-            ]);
+            // Which characters of the display name matched the current query.
+            let haystack = Utf32String::from(display_name.as_str());
+            indices.clear();
+            pattern.indices(haystack.slice(..), &mut matcher, &mut indices);
+            indices.sort_unstable();
+            indices.dedup();
 
-            ListItem::new(line)
+            let mut spans = vec![Span::styled(marker, name_style)];
+            spans.extend(highlight_spans(
+                display_name,
+                &indices,
+                name_style,
+                matched_style,
+            ));
+            let pad = name_w.saturating_sub(display_name.chars().count());
+            if pad > 0 {
+                spans.push(Span::styled(" ".repeat(pad), name_style));
+            }
+            spans.push(Span::styled(format!("{:<ver_w$}", pkg.version), ver_style));
+            spans.push(Span::styled(pkg.description.clone(), desc_style));
+
+            ListItem::new(Line::from(spans))
         })
         .collect();
 